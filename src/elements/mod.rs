@@ -16,6 +16,8 @@ use std::fs::File;
 use std::io::{BufReader, BufRead, Error, ErrorKind};
 use std::path::{Path, PathBuf};
 
+pub mod reaction;
+
 // elements.txt
 const elements_path: &str = "./src/elements/elements.txt";
 
@@ -35,12 +37,86 @@ const m_neutron: f64 = 939.573; // MeV/c^2
 const amu: f64 = 931.5; // 1 amu = 931.5 MeV/c^2 = 1.661 * 10e-27 kg
 
 // ---------------------------- liquid drop Model constants ------------
+//
+// The SEMF coefficients themselves live on 'LiquidDropParams' below, since
+// different textbooks and fits disagree on their values.
+
+#[derive(Debug, Clone, Copy)]
+pub struct LiquidDropParams{
+    pub A_V: f64,             // MeV (volume coefficient)
+    pub A_S: f64,             // MeV (surface area coefficient)
+    pub A_C: f64,             // MeV (coulomb coefficient)
+    pub A_A: f64,             // MeV (asymmetry term coefficient)
+    pub A_P: f64,             // MeV (pairing term coefficient)
+    pub pairing_exponent: f64, // exponent on A in the pairing term, e.g. -1/2 or -3/4
+    pub m_proton: f64,        // MeV/c^2
+    pub m_neutron: f64,       // MeV/c^2
+}
+
+impl LiquidDropParams{
+    pub fn canonical() -> Self{
+        // The crate's longstanding coefficient values (Krane, "Introductory
+        // Nuclear Physics"), with the pairing term scaled as A^(-1/2)
+        Self{
+            A_V: 15.5,
+            A_S: 16.8,
+            A_C: 0.72,
+            A_A: 23.0,
+            A_P: 34.0,
+            pairing_exponent: -1.0 / 2.0,
+            m_proton,
+            m_neutron,
+        }
+    }
+
+    pub fn wapstra() -> Self{
+        // Wapstra (1971) mass formula fit
+        Self{
+            A_V: 14.1,
+            A_S: 13.0,
+            A_C: 0.595,
+            A_A: 19.3,
+            A_P: 33.5,
+            pairing_exponent: -3.0 / 4.0,
+            m_proton,
+            m_neutron,
+        }
+    }
 
-const A_V: f64 = 15.5; // MeV (volume coefficient)
-const A_S: f64 = 16.8; // MeV (surface area coeffiecient)
-const A_C: f64 = 0.72; // eV (coulomb coefficient)
-const A_A: f64 = 23.0; // MeV (asymmetry term coefficient)
-const A_P: f64 = 34.0; // MeV (pairing term coefficient)    
+    pub fn rohlf() -> Self{
+        // Rohlf, "Modern Physics from alpha to Z0"
+        Self{
+            A_V: 15.75,
+            A_S: 17.8,
+            A_C: 0.711,
+            A_A: 23.7,
+            A_P: 11.18,
+            pairing_exponent: -3.0 / 4.0,
+            m_proton,
+            m_neutron,
+        }
+    }
+
+    pub fn myers_swiatecki() -> Self{
+        // Myers & Swiatecki (1966) droplet-model fit
+        Self{
+            A_V: 15.677,
+            A_S: 18.56,
+            A_C: 0.717,
+            A_A: 23.6,
+            A_P: 11.2,
+            pairing_exponent: -1.0 / 2.0,
+            m_proton,
+            m_neutron,
+        }
+    }
+}
+
+impl Default for LiquidDropParams{
+    fn default() -> Self{
+        LiquidDropParams::canonical()
+    }
+}
 
 
 pub fn get_element_from_protons(num_protons: i32) -> Result<(String, String), Error>{
@@ -92,54 +168,415 @@ pub fn get_element_from_protons(num_protons: i32) -> Result<(String, String), Er
                       .unwrap_or("")
                       .to_string();        // String, owned by 'symbol'
 
-    Ok((element, symbol))   // Result<(String, String)> 
+    Ok((element, symbol))   // Result<(String, String)>
+}
+
+pub fn get_protons_from_symbol(symbol: &str) -> Result<i32, Error>{
+    /// Retrieves the proton number (atomic number) of a chemical element based on its symbol.
+    ///
+    /// This is the reverse of `get_element_from_protons`: it scans the same
+    /// `elements.txt` data for a matching symbol column (case-insensitive).
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The element's chemical symbol, e.g. "U" or "Pd".
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(num_protons)` - The element's proton number.
+    /// * `Err(Error)` - If the symbol is not found or the elements file cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Err` in the following cases:
+    /// * If the elements file cannot be opened or read from.
+    /// * If no element in the file has the given symbol.
+
+    let file = File::open(elements_path)?;
+    let reader = BufReader::new(file);
+
+    let collection: Vec<String> = reader.lines().collect::<Result<_, _>>()?;
+
+    for (index, line) in collection.iter().enumerate() {
+        let mut parts = line.split(',');
+        let _element = parts.next().unwrap_or("");
+        let line_symbol = parts.next().unwrap_or("");
+
+        if line_symbol.eq_ignore_ascii_case(symbol) {
+            return Ok((index + 1) as i32);
+        }
+    }
+
+    Err(Error::new(ErrorKind::Other, format!("Unknown element symbol '{}'", symbol)))
+}
+
+fn parse_nuclide(name: &str) -> Result<Isotope, Error>{
+    // Parses the compact "el+A" nuclide notation used by 'load_network',
+    // e.g. "n", "p", "d", "t", "he4", "u236", "pd117".
+
+    match name.to_ascii_lowercase().as_str() {
+        "n" => return Ok(Isotope::neutron()),
+        "p" => return Ok(Isotope::from_nucleons(1, 1)),
+        "d" => return Ok(Isotope::from_nucleons(2, 1)),
+        "t" => return Ok(Isotope::from_nucleons(3, 1)),
+        _ => {}
+    }
+
+    let split_at = name.find(|c: char| c.is_ascii_digit())
+        .ok_or_else(|| Error::new(ErrorKind::Other, format!("Malformed nuclide '{}'", name)))?;
+
+    let (symbol, mass_number) = name.split_at(split_at);
+
+    let A: i32 = mass_number.parse()
+        .map_err(|_| Error::new(ErrorKind::Other, format!("Malformed mass number in '{}'", name)))?;
+
+    let Z = get_protons_from_symbol(symbol)?;
+
+    Ok(Isotope::from_nucleons(A, Z))
+}
+
+pub fn load_network(path: &Path) -> Result<Vec<Isotope>, Error>{
+    /// Loads a collection of `Isotope`s from a newline-delimited file of
+    /// compact nuclide names (e.g. `n`, `p`, `he4`, `u236`, `pd117`), akin to
+    /// WinNet's `load_network`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the network file, one nuclide per line.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(isotopes)` - The `Isotope`s in file order.
+    /// * `Err(Error)` - If the file cannot be read or a line is not a valid nuclide.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Err` in the following cases:
+    /// * If the network file cannot be opened or read from.
+    /// * If a line does not parse as a known nuclide.
+
+    let file = File::open(path)?;
+    let reader = BufReader::new(file);
+
+    let mut isotopes = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        isotopes.push(parse_nuclide(trimmed)?);
+    }
+
+    Ok(isotopes)
+}
+
+pub fn most_stable_Z(A: i32) -> i32{
+    /// Finds the proton number Z that minimizes the nuclear mass at fixed A.
+    ///
+    /// Holding A constant and substituting N = A − Z into `binding_energy()`
+    /// turns the liquid-drop mass M(Z) into a quadratic (parabola) in Z. Setting
+    /// dM/dZ = 0 and ignoring the (discontinuous) pairing term gives the
+    /// continuous optimum
+    ///
+    ///   Z* = [(m_neutron − m_proton) + 4·A_A] / [2·(A_C·A^(−1/3) + 4·A_A/A)]
+    ///
+    /// `Z*` is rounded and the two neighboring integer Z values are also
+    /// checked directly against `mass()`, since the pairing term's even-odd
+    /// zig-zag can shift the true minimum off the continuous optimum.
+    ///
+    /// # Arguments
+    ///
+    /// * `A` - The mass number of the isobar chain to search.
+    ///
+    /// # Returns
+    ///
+    /// * The proton number Z of the lowest-mass isobar for the given A.
+
+    let A_f64 = A as f64;
+    let params = LiquidDropParams::canonical();
+
+    let Z_star = ((params.m_neutron - params.m_proton) + 4.0 * params.A_A)
+        / (2.0 * (params.A_C * A_f64.powf(-1.0 / 3.0) + 4.0 * params.A_A / A_f64));
+
+    let Z_round = Z_star.round() as i32;
+
+    // 'elements.txt' only covers Z = 1-118, so clamp the search window there
+    // too -- from about A = 318 the continuous optimum itself exceeds Z=118.
+    let Z_clamped = Z_round.clamp(0, A.min(118));
+
+    (Z_clamped - 1..=Z_clamped + 1)
+        .filter(|&Z| Z >= 0 && Z <= A && Z <= 118)
+        .min_by(|&a, &b| {
+            let mass_a = Isotope::from_nucleons(A, a).mass();
+            let mass_b = Isotope::from_nucleons(A, b).mass();
+            mass_a.partial_cmp(&mass_b).unwrap()
+        })
+        .unwrap_or(Z_clamped)
 }
- 
+
+fn is_plausible_A(A: i32, Z: i32) -> bool{
+    // Rough physical bound on N/Z used to tell a real mass number apart from
+    // one still carrying a ZAID metastable offset (see 'Isotope::from_zaid').
+    if Z == 0 {
+        return A == 1; // only the free neutron
+    }
+
+    let N = A - Z;
+    N >= 0 && (N as f64) <= 1.8 * (Z as f64) + 10.0
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecayMode{
+    BetaMinus,
+    BetaPlusElectronCapture,
+    Stable,
+}
+
+pub struct FissionResult{
+    pub Q: f64,
+    pub neutrons_emitted: i32,
+    pub binding_energy_per_nucleon_gained: f64,
+}
+
 pub struct Isotope{
     element: String,
     symbol: String,
     A: i32,
     Z: i32,
     N: i32,
+    metastable_level: Option<u8>,
 }
 
 impl Isotope{
     pub fn from_nucleons(A: i32, Z: i32) -> Self{
-        let Ok((element, symbol)) = get_element_from_protons(Z) else { todo!() };
         let N = A - Z;
+
+        // Z = 0 is a bare neutron and has no entry in 'elements.txt', so it
+        // has to be special-cased ahead of the lookup.
+        let (element, symbol) = if Z == 0 {
+            ("Neutron".to_string(), "n".to_string())
+        } else {
+            let Ok((element, symbol)) = get_element_from_protons(Z) else { todo!() };
+            (element, symbol)
+        };
+
         Self{
-            element, symbol, A, Z, N
+            element, symbol, A, Z, N, metastable_level: None
         }
     }
-    
-    pub fn get_upper_isobar(&self) -> Self{
-        Isotope::from_nucleons(self.A, self.Z + 1) 
+
+    pub fn neutron() -> Self{
+        // Bare neutron: A = 1, Z = 0
+        Isotope::from_nucleons(1, 0)
+    }
+
+    pub fn proton() -> Self{
+        // Bare proton: A = 1, Z = 1
+        Isotope::from_nucleons(1, 1)
+    }
+
+    pub fn from_zaid(zaid: i32) -> Result<Self, Error>{
+        /// Parses an ACE/OpenMC-style ZAID identifier, `zaid = 1000*Z + A`.
+        ///
+        /// Metastable isomers are encoded by real nuclear-data libraries by
+        /// adding `300 + 100*level` to A (so e.g. Am-242m1 appears as A=642).
+        /// This detects when the decoded A is implausibly large for the given
+        /// Z and peels off that offset, recording the isomer level.
+        ///
+        /// # Errors
+        ///
+        /// Returns an `Err` if the decoded `Z` is not a bare neutron (`Z = 0`)
+        /// and falls outside the valid element range of 1-118, or if no A
+        /// (with or without a metastable offset peeled off) is plausible for
+        /// the decoded `Z`.
+
+        let Z = zaid / 1000;
+        let raw_A = zaid % 1000;
+
+        if Z != 0 && !(1..=118).contains(&Z) {
+            return Err(Error::new(ErrorKind::Other, format!("ZAID '{}' decodes to Z = {}, out of range 0, 1-118", zaid, Z)));
+        }
+
+        let (A, metastable_level) = if is_plausible_A(raw_A, Z){
+            (raw_A, None)
+        } else {
+            (1..=9u8)
+                .find_map(|level| {
+                    let candidate_A = raw_A - (300 + 100 * level as i32);
+                    is_plausible_A(candidate_A, Z).then_some((candidate_A, Some(level)))
+                })
+                .ok_or_else(|| Error::new(ErrorKind::Other, format!("ZAID '{}' does not decode to a plausible nuclide for Z = {}", zaid, Z)))?
+        };
+
+        let mut isotope = Isotope::from_nucleons(A, Z);
+        isotope.metastable_level = metastable_level;
+        Ok(isotope)
+    }
+
+    pub fn to_zaid(&self) -> i32{
+        // Inverse of 'from_zaid': zaid = 1000*Z + A, plus the metastable offset.
+        let offset = match self.metastable_level {
+            Some(level) => 300 + 100 * level as i32,
+            None => 0,
+        };
+
+        1000 * self.Z + self.A + offset
+    }
+
+    pub fn get_upper_isobar(&self) -> Option<Self>{
+        // 'elements.txt' only covers Z = 1-118, so there is no upper isobar
+        // past Oganesson (Z=118).
+        (self.Z + 1 <= 118).then(|| Isotope::from_nucleons(self.A, self.Z + 1))
+    }
+
+    pub fn get_lower_isobar(&self) -> Option<Self>{
+        // Z = 0 is the bare neutron, so there is no lower isobar below it.
+        (self.Z - 1 >= 0).then(|| Isotope::from_nucleons(self.A, self.Z - 1))
+    }
+
+    pub fn beta_minus_q(&self) -> Option<f64>{
+        /// beta-minus decay: (A,Z) -> (A,Z+1) + e- + antineutrino
+        ///
+        /// `mass()` is a bare nuclear mass (no atomic electrons), so the
+        /// single emitted electron's mass is subtracted directly.
+        ///
+        /// Returns `None` if the upper isobar would fall outside Z = 1-118.
+        self.get_upper_isobar().map(|upper| self.mass() - upper.mass() - m_electron)
+    }
+
+    pub fn beta_plus_q(&self) -> Option<f64>{
+        /// beta-plus decay: (A,Z) -> (A,Z-1) + e+ + neutrino
+        ///
+        /// Unlike the atomic-mass convention (which subtracts `2*m_electron`
+        /// to account for the daughter keeping one fewer atomic electron),
+        /// this crate's `mass()` has no atomic electrons to begin with, so
+        /// only the emitted positron's mass is subtracted.
+        ///
+        /// Returns `None` for Z = 0, which has no lower isobar; for Z = 1 the
+        /// lower isobar is the Z = 0 (neutron) case.
+        self.get_lower_isobar().map(|lower| self.mass() - lower.mass() - m_electron)
+    }
+
+    pub fn electron_capture_q(&self) -> Option<f64>{
+        /// electron capture: (A,Z) + e- -> (A,Z-1) + neutrino
+        ///
+        /// The captured electron comes from outside the nucleus, so under
+        /// this crate's electron-free `mass()` convention its mass is added
+        /// back on the reactant side (the atomic-mass convention omits this
+        /// term because it is already folded into the atomic masses).
+        ///
+        /// Returns `None` for Z = 0, which has no lower isobar; for Z = 1 the
+        /// lower isobar is the Z = 0 (neutron) case.
+        self.get_lower_isobar().map(|lower| self.mass() + m_electron - lower.mass())
+    }
+
+    pub fn predict_decay(&self) -> DecayMode{
+        // Compares this isobar's mass to its neighbors to predict which way,
+        // if any, it decays toward the valley of stability. Beta-plus decay
+        // and electron capture compete for the same Z -> Z-1 transition, so
+        // either channel being exothermic makes the isotope unstable that way.
+        if self.beta_minus_q().is_some_and(|q| q > 0.0){
+            DecayMode::BetaMinus
+        } else if self.beta_plus_q().is_some_and(|q| q > 0.0) || self.electron_capture_q().is_some_and(|q| q > 0.0){
+            DecayMode::BetaPlusElectronCapture
+        } else {
+            DecayMode::Stable
+        }
+    }
+
+    pub fn most_stable_isobar(&self) -> Self{
+        // Walks the mass parabola at fixed A and returns the beta-stable nucleus
+        Isotope::from_nucleons(self.A, most_stable_Z(self.A))
+    }
+
+    pub fn fission_into(&self, fragments: &[Isotope]) -> Result<FissionResult, Error>{
+        /// Fissions this nucleus into 'fragments', inferring the number of
+        /// prompt neutrons emitted from the mass-number deficit.
+        ///
+        /// # Errors
+        ///
+        /// Returns an `Err` if the fragments do not conserve Z, or if they
+        /// exceed the parent's mass number A.
+
+        let fragment_A: i32 = fragments.iter().map(|fragment| fragment.A).sum();
+        let fragment_Z: i32 = fragments.iter().map(|fragment| fragment.Z).sum();
+
+        if fragment_Z != self.Z {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Fission fragments do not conserve Z: {} -> {}", self.Z, fragment_Z),
+            ));
+        }
+
+        let neutrons_emitted = self.A - fragment_A;
+        if neutrons_emitted < 0 {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("Fission fragments exceed parent's mass number: {} -> {}", self.A, fragment_A),
+            ));
+        }
+
+        // Q via mass differences, the same way 'reaction::reaction_energy'
+        // computes it -- free neutrons contribute their bare mass and no
+        // binding energy, so they must not be run through the SEMF, which is
+        // only valid for bound multi-nucleon systems.
+        let fragment_mass: f64 = fragments.iter().map(Isotope::mass).sum();
+        let product_mass = fragment_mass + neutrons_emitted as f64 * Isotope::neutron().mass();
+
+        let Q = self.mass() - product_mass;
+
+        let fragment_binding_energy: f64 = fragments.iter().map(|fragment| fragment.binding_energy(None)).sum();
+        let product_binding_energy_per_nucleon = fragment_binding_energy / self.A as f64;
+        let binding_energy_per_nucleon_gained =
+            product_binding_energy_per_nucleon - self.binding_energy_per_nucleon();
+
+        Ok(FissionResult{
+            Q,
+            neutrons_emitted,
+            binding_energy_per_nucleon_gained,
+        })
     }
 
     pub fn mass(&self) -> f64{
         // returns the mass in Mev/c^2 units, divide by 'amu' to get amu units
         (self.Z as f64) * m_proton +
         (self.N as f64) * m_neutron -
-        self.binding_energy()
+        self.binding_energy(None)
     }
-    
-    pub fn binding_energy(&self) -> f64{
+
+    pub fn binding_energy(&self, params: Option<LiquidDropParams>) -> f64{
         /// Liquid Drop Model
         /// Implements the Bethe-Weinsaecker semi-empirical mass formula for binding energy
         ///
-        /// The calculation is performed in units of MeV and can be converted afterward to 
+        /// The calculation is performed in units of MeV and can be converted afterward to
         /// other units to not clutter the calculation. Only the coloumb term is scaled.
-        
-        let Isotope {A, Z, N, ..} = self; 
+        ///
+        /// `params` selects the SEMF coefficients (see `LiquidDropParams`);
+        /// `None` uses `LiquidDropParams::canonical()`.
+
+        let params = params.unwrap_or_default();
+
+        let Isotope {A, Z, N, ..} = self;
+
+        // A lone nucleon (free proton or neutron) is unbound, so it carries no
+        // binding energy and 'mass()' is its bare mass.
+        if *A <= 1 {
+            return 0.0;
+        }
+
         let A_f64: f64 = *A as f64;
         let Z_f64: f64 = *Z as f64;
         let N_f64: f64 = *N as f64;
 
-        let volume = A_V * A_f64;
-        let surface = -1.0 * A_S * A_f64.powf(2.0 / 3.0);
-        let coulomb = -1.0 * A_C * Z_f64.powf(2.0) * A_f64.powf(-1.0 / 3.0); 
-        let asymetry = -1.0 * A_A * (N_f64 - Z_f64).powf(2.0)/A_f64;
-        let delta = A_P * A_f64.powf(-3.0 / 4.0);
+        let volume = params.A_V * A_f64;
+        let surface = -1.0 * params.A_S * A_f64.powf(2.0 / 3.0);
+        let coulomb = -1.0 * params.A_C * Z_f64.powf(2.0) * A_f64.powf(-1.0 / 3.0);
+        let asymetry = -1.0 * params.A_A * (N_f64 - Z_f64).powf(2.0)/A_f64;
+        let delta = params.A_P * A_f64.powf(params.pairing_exponent);
 
         let pairing ={
             if Z % 2 == 0 && N % 2 == 0{            // Even Z / Even N
@@ -149,17 +586,17 @@ impl Isotope{
             } else if Z % 2 != 0 && N % 2 != 0{     // Odd Z / Odd N
                 -1.0 * delta
             } else {                                // Not a common case
-                0.0    
-            } 
+                0.0
+            }
         };
-    
+
         volume + surface + coulomb + asymetry + pairing // return the binding energy
     }
 
     pub fn binding_energy_per_nucleon(&self) -> f64{
         // Returns the binding energy per nucleon of the Isotope
-    
-        self.binding_energy() / self.A as f64     
+
+        self.binding_energy(None) / self.A as f64
     }
 
     pub fn report(&self) {
@@ -181,7 +618,7 @@ impl Isotope{
                  "N", self.N,
                  "Mass (amu)", self.mass() / amu,
                  "", self.mass(),
-                 "Binding Energy:", self.binding_energy(),
+                 "Binding Energy:", self.binding_energy(None),
                  "BE/A:", self.binding_energy_per_nucleon(),
                 ); 
     }
@@ -0,0 +1,52 @@
+
+/*
+ * Benjamin Waters
+ * Nuclear Physics
+ *
+ * Reaction Module
+ * 9/15/2024
+ *
+ */
+
+use std::io::{Error, ErrorKind};
+
+use super::Isotope;
+
+pub fn reaction_energy(reactants: &[Isotope], products: &[Isotope]) -> Result<f64, Error>{
+    /// Computes the Q-value of a nuclear reaction, in MeV.
+    ///
+    /// Given the reactant and product `Isotope`s (bare nucleons are fine too,
+    /// see `Isotope::neutron()` / `Isotope::proton()`), this first checks that
+    /// mass number A and proton number Z balance on both sides of the
+    /// reaction, then returns Q = Σ mass(reactants) − Σ mass(products).
+    /// A positive Q means energy is released (exothermic, e.g. fusion/fission),
+    /// a negative Q means energy must be supplied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the reactants and products do not conserve A or Z.
+
+    let (reactant_A, reactant_Z) = sum_nucleons(reactants);
+    let (product_A, product_Z) = sum_nucleons(products);
+
+    if reactant_A != product_A || reactant_Z != product_Z {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "Reaction does not conserve nucleons: A {} -> {}, Z {} -> {}",
+                reactant_A, product_A, reactant_Z, product_Z
+            ),
+        ));
+    }
+
+    let reactant_mass: f64 = reactants.iter().map(Isotope::mass).sum();
+    let product_mass: f64 = products.iter().map(Isotope::mass).sum();
+
+    Ok(reactant_mass - product_mass)
+}
+
+fn sum_nucleons(isotopes: &[Isotope]) -> (i32, i32){
+    isotopes.iter().fold((0, 0), |(A, Z), isotope| {
+        (A + isotope.A, Z + isotope.Z)
+    })
+}